@@ -2,8 +2,15 @@
 
 use std::collections::HashMap;
 use std::net::IpAddr;
+use std::path::Path;
 use std::time::{Duration, SystemTime};
 
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "pcap")]
+use std::borrow::Cow;
+#[cfg(feature = "pcap")]
+use std::collections::BTreeMap;
 #[cfg(feature = "pcap")]
 use std::net::{Ipv4Addr, Ipv6Addr};
 #[cfg(feature = "pcap")]
@@ -22,12 +29,57 @@ const CHANNEL_CAPACITY: usize = 1000;
 const CACHE_MAX_ENTRIES: usize = 10_000;
 const DNS_PORT: u16 = 53;
 const TLS_SNI_PORT: u16 = 443;
+const DHCP_SERVER_PORT: u16 = 67;
+const DHCP_CLIENT_PORT: u16 = 68;
+const DHCP_MAGIC_COOKIE: [u8; 4] = [99, 130, 83, 99];
+const DHCP_OPT_SUBNET_MASK: u8 = 1;
+const DHCP_OPT_ROUTER: u8 = 3;
+const DHCP_OPT_DNS_SERVER: u8 = 6;
+const DHCP_OPT_END: u8 = 255;
+const DHCP_INFRA_TTL_SECS: u64 = 3600;
 const MAX_DNS_PTR_DEPTH: usize = 6;
+const TLS_REASSEMBLY_MAX_BYTES: usize = 16 * 1024;
+const TLS_REASSEMBLY_IDLE_SECS: u64 = 30;
+const TCP_FLAG_FIN: u8 = 0x01;
+const TCP_FLAG_RST: u8 = 0x04;
+const IP_REASSEMBLY_IDLE_SECS: u64 = 30;
+const IPV6_MAX_EXT_HEADERS: usize = 8;
+const IPV6_NH_HOP_BY_HOP: u8 = 0;
+const IPV6_NH_TCP: u8 = 6;
+const IPV6_NH_UDP: u8 = 17;
+const IPV6_NH_ROUTING: u8 = 43;
+const IPV6_NH_FRAGMENT: u8 = 44;
+const IPV6_NH_DEST_OPTIONS: u8 = 60;
+const IPV6_NH_NO_NEXT_HEADER: u8 = 59;
+
+const QUIC_VERSION_1: u32 = 0x0000_0001;
+const QUIC_V1_INITIAL_SALT: [u8; 20] = [
+    0x38, 0x76, 0x2c, 0xf7, 0xf5, 0x59, 0x34, 0xb3, 0x4d, 0x17, 0x9a, 0xe6, 0xa4, 0xc8, 0x0c, 0xad,
+    0xcc, 0xbb, 0x7f, 0x0a,
+];
+const QUIC_CRYPTO_MAX_BYTES: usize = 16 * 1024;
+const QUIC_CRYPTO_IDLE_SECS: u64 = 10;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InfraRole {
+    Resolver,
+    Gateway,
+}
+
+impl InfraRole {
+    fn label(self) -> &'static str {
+        match self {
+            InfraRole::Resolver => "resolver",
+            InfraRole::Gateway => "gateway",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum DomainSource {
     Dns,
     Sni,
+    Infrastructure(InfraRole),
 }
 
 #[derive(Clone, Debug)]
@@ -38,6 +90,24 @@ pub struct DomainMapping {
     ttl: Duration,
 }
 
+// On-disk representation of a single `DomainMapping`. `captured_at` is
+// stored as a unix timestamp and `ttl` as whole seconds so the cache file
+// stays a plain, human-readable JSON document across platforms.
+#[derive(Serialize, Deserialize)]
+struct SerializedMapping {
+    ip: IpAddr,
+    port: Option<u16>,
+    hostname: String,
+    source: DomainSource,
+    captured_at_unix: u64,
+    ttl_secs: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SerializedCache {
+    entries: Vec<SerializedMapping>,
+}
+
 pub struct DomainCache {
     by_ip_port: HashMap<(IpAddr, u16), DomainMapping>,
     by_ip: HashMap<IpAddr, DomainMapping>,
@@ -76,6 +146,7 @@ impl DomainCache {
         match msg {
             PcapMsg::DnsMapping { ip, hostname } => self.insert_dns(ip, hostname),
             PcapMsg::SniMapping { ip, port, hostname } => self.insert_sni(ip, port, hostname),
+            PcapMsg::DhcpMapping { ip, role } => self.insert_infrastructure(ip, role),
         }
     }
 
@@ -90,6 +161,17 @@ impl DomainCache {
         self.prune_if_needed();
     }
 
+    fn insert_infrastructure(&mut self, ip: IpAddr, role: InfraRole) {
+        let mapping = DomainMapping {
+            hostname: role.label().to_string(),
+            source: DomainSource::Infrastructure(role),
+            captured_at: SystemTime::now(),
+            ttl: Duration::from_secs(DHCP_INFRA_TTL_SECS),
+        };
+        self.by_ip.insert(ip, mapping);
+        self.prune_if_needed();
+    }
+
     fn insert_sni(&mut self, ip: IpAddr, port: u16, hostname: String) {
         let mapping = DomainMapping {
             hostname,
@@ -114,11 +196,9 @@ impl DomainCache {
     }
 
     fn prune_if_needed(&mut self) {
-        let total = self.by_ip.len() + self.by_ip_port.len();
-        if total <= self.max_entries {
-            return;
+        while self.by_ip.len() + self.by_ip_port.len() > self.max_entries {
+            self.remove_oldest();
         }
-        self.remove_oldest();
     }
 
     fn remove_oldest(&mut self) {
@@ -153,6 +233,181 @@ impl DomainCache {
             }
         }
     }
+
+    // Write every mapping (expired or not) to `path` as JSON so the cache
+    // can be restored across restarts. Expiry is applied on load, not here,
+    // so a save always captures the cache's true current state.
+    pub fn save_to(&self, path: &Path) -> Result<(), String> {
+        let mut entries = Vec::with_capacity(self.by_ip.len() + self.by_ip_port.len());
+        for (ip, mapping) in &self.by_ip {
+            entries.push(SerializedMapping {
+                ip: *ip,
+                port: None,
+                hostname: mapping.hostname.clone(),
+                source: mapping.source,
+                captured_at_unix: unix_secs(mapping.captured_at),
+                ttl_secs: mapping.ttl.as_secs(),
+            });
+        }
+        for ((ip, port), mapping) in &self.by_ip_port {
+            entries.push(SerializedMapping {
+                ip: *ip,
+                port: Some(*port),
+                hostname: mapping.hostname.clone(),
+                source: mapping.source,
+                captured_at_unix: unix_secs(mapping.captured_at),
+                ttl_secs: mapping.ttl.as_secs(),
+            });
+        }
+
+        let json = serde_json::to_string_pretty(&SerializedCache { entries })
+            .map_err(|e| format!("failed to serialize domain cache: {e}"))?;
+        std::fs::write(path, json)
+            .map_err(|e| format!("failed to write domain cache to {}: {e}", path.display()))
+    }
+
+    // Load a cache previously written by `save_to`, dropping any entry that
+    // has already expired per `is_expired` so stale data isn't resurrected.
+    pub fn load_from(path: &Path) -> Result<Self, String> {
+        let data = std::fs::read_to_string(path)
+            .map_err(|e| format!("failed to read domain cache from {}: {e}", path.display()))?;
+        let serialized: SerializedCache = serde_json::from_str(&data)
+            .map_err(|e| format!("failed to parse domain cache from {}: {e}", path.display()))?;
+
+        let mut cache = Self::new();
+        let now = SystemTime::now();
+        for entry in serialized.entries {
+            let mapping = DomainMapping {
+                hostname: entry.hostname,
+                source: entry.source,
+                captured_at: SystemTime::UNIX_EPOCH + Duration::from_secs(entry.captured_at_unix),
+                ttl: Duration::from_secs(entry.ttl_secs),
+            };
+            if is_expired(&mapping, now) {
+                continue;
+            }
+            match entry.port {
+                Some(port) => {
+                    cache.by_ip_port.insert((entry.ip, port), mapping);
+                }
+                None => {
+                    cache.by_ip.insert(entry.ip, mapping);
+                }
+            }
+        }
+        cache.prune_if_needed();
+        Ok(cache)
+    }
+}
+
+// Round trip, expiry-on-load, and bulk-prune coverage for save_to/load_from,
+// per the backlog request that added them.
+#[cfg(test)]
+mod domain_cache_tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    fn temp_cache_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!(
+            "rano_domain_cache_test_{}_{name}.json",
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let path = temp_cache_path("round_trip");
+        let mut cache = DomainCache::new();
+        cache.insert_dns(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)), "dns.example".into());
+        cache.insert_sni(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2)), 443, "sni.example".into());
+        cache.insert_infrastructure(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 3)), InfraRole::Gateway);
+
+        cache.save_to(&path).expect("save_to should succeed");
+        let mut loaded = DomainCache::load_from(&path).expect("load_from should succeed");
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(
+            loaded.lookup(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)), 0),
+            Some("dns.example".to_string())
+        );
+        assert_eq!(
+            loaded.lookup(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2)), 443),
+            Some("sni.example".to_string())
+        );
+        assert_eq!(
+            loaded.lookup(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 3)), 0),
+            Some("gateway".to_string())
+        );
+    }
+
+    #[test]
+    fn load_from_drops_already_expired_entries() {
+        let path = temp_cache_path("expiry");
+        let serialized = SerializedCache {
+            entries: vec![
+                SerializedMapping {
+                    ip: IpAddr::V4(Ipv4Addr::new(10, 0, 1, 1)),
+                    port: None,
+                    hostname: "stale.example".to_string(),
+                    source: DomainSource::Dns,
+                    // captured long enough ago, with a short ttl, that it's
+                    // already expired by the time load_from checks it.
+                    captured_at_unix: 1,
+                    ttl_secs: 1,
+                },
+                SerializedMapping {
+                    ip: IpAddr::V4(Ipv4Addr::new(10, 0, 1, 2)),
+                    port: None,
+                    hostname: "fresh.example".to_string(),
+                    source: DomainSource::Dns,
+                    captured_at_unix: unix_secs(SystemTime::now()),
+                    ttl_secs: DNS_TTL_SECS,
+                },
+            ],
+        };
+        let json = serde_json::to_string_pretty(&serialized).unwrap();
+        std::fs::write(&path, json).unwrap();
+
+        let mut loaded = DomainCache::load_from(&path).expect("load_from should succeed");
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(loaded.lookup(IpAddr::V4(Ipv4Addr::new(10, 0, 1, 1)), 0), None);
+        assert_eq!(
+            loaded.lookup(IpAddr::V4(Ipv4Addr::new(10, 0, 1, 2)), 0),
+            Some("fresh.example".to_string())
+        );
+    }
+
+    // load_from bulk-inserts every saved entry before pruning once, the same
+    // shape this test exercises directly against prune_if_needed.
+    #[test]
+    fn prune_if_needed_evicts_down_to_max_entries_after_bulk_insert() {
+        let mut cache = DomainCache::new();
+        cache.max_entries = 3;
+        let now = SystemTime::now();
+        for i in 0..10u8 {
+            cache.by_ip.insert(
+                IpAddr::V4(Ipv4Addr::new(10, 0, 2, i)),
+                DomainMapping {
+                    hostname: format!("host-{i}"),
+                    source: DomainSource::Dns,
+                    captured_at: now + Duration::from_secs(i as u64),
+                    ttl: Duration::from_secs(DNS_TTL_SECS),
+                },
+            );
+        }
+        assert_eq!(cache.by_ip.len(), 10);
+
+        cache.prune_if_needed();
+
+        assert_eq!(cache.by_ip.len() + cache.by_ip_port.len(), 3);
+    }
+}
+
+fn unix_secs(time: SystemTime) -> u64 {
+    time.duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
 }
 
 fn is_expired(mapping: &DomainMapping, now: SystemTime) -> bool {
@@ -165,6 +420,7 @@ fn is_expired(mapping: &DomainMapping, now: SystemTime) -> bool {
 pub enum PcapMsg {
     DnsMapping { ip: IpAddr, hostname: String },
     SniMapping { ip: IpAddr, port: u16, hostname: String },
+    DhcpMapping { ip: IpAddr, role: InfraRole },
 }
 
 pub struct PcapHandle {
@@ -214,8 +470,24 @@ pub fn pcap_supported() -> bool {
     cfg!(feature = "pcap")
 }
 
+#[derive(Clone, Copy, Debug)]
+pub struct PcapCaptureConfig {
+    // Verify IPv4/UDP/TCP checksums and drop packets that fail. Packets with
+    // a zeroed checksum field (common with NIC checksum offload) are passed
+    // through rather than treated as invalid.
+    pub verify_checksums: bool,
+}
+
+impl Default for PcapCaptureConfig {
+    fn default() -> Self {
+        Self {
+            verify_checksums: true,
+        }
+    }
+}
+
 #[cfg(feature = "pcap")]
-pub fn start_pcap_capture() -> Result<PcapHandle, String> {
+pub fn start_pcap_capture(config: PcapCaptureConfig) -> Result<PcapHandle, String> {
     use pcap::{Capture, Device};
 
     let device = Device::lookup()
@@ -228,7 +500,12 @@ pub fn start_pcap_capture() -> Result<PcapHandle, String> {
         .open()
         .map_err(|e| format!("pcap capture open failed: {e}"))?;
 
-    cap.filter("udp port 53 or tcp port 53 or tcp port 443", true)
+    let filter = if cfg!(feature = "quic") {
+        "udp port 53 or tcp port 53 or tcp port 443 or udp port 443 or udp port 67 or udp port 68"
+    } else {
+        "udp port 53 or tcp port 53 or tcp port 443 or udp port 67 or udp port 68"
+    };
+    cap.filter(filter, true)
         .map_err(|e| format!("pcap filter failed: {e}"))?;
 
     let cap = cap
@@ -239,21 +516,24 @@ pub fn start_pcap_capture() -> Result<PcapHandle, String> {
     let stop = Arc::new(AtomicBool::new(false));
     let stop_thread = stop.clone();
 
-    let handle = std::thread::spawn(move || loop {
-        if stop_thread.load(Ordering::SeqCst) {
-            break;
-        }
-        match cap.next_packet() {
-            Ok(packet) => {
-                if let Some(tp) = parse_transport_packet(packet.data) {
-                    handle_transport_packet(tp, &sender);
-                }
-            }
-            Err(pcap::Error::TimeoutExpired) => {
-                std::thread::sleep(Duration::from_millis(10));
+    let handle = std::thread::spawn(move || {
+        let mut state = CaptureState::new(config.verify_checksums);
+        loop {
+            if stop_thread.load(Ordering::SeqCst) {
+                break;
             }
-            Err(_) => {
-                std::thread::sleep(Duration::from_millis(50));
+            match cap.next_packet() {
+                Ok(packet) => {
+                    if let Some(tp) = parse_transport_packet(packet.data, &mut state) {
+                        handle_transport_packet(tp, &sender, &mut state);
+                    }
+                }
+                Err(pcap::Error::TimeoutExpired) => {
+                    std::thread::sleep(Duration::from_millis(10));
+                }
+                Err(_) => {
+                    std::thread::sleep(Duration::from_millis(50));
+                }
             }
         }
     });
@@ -266,7 +546,7 @@ pub fn start_pcap_capture() -> Result<PcapHandle, String> {
 }
 
 #[cfg(not(feature = "pcap"))]
-pub fn start_pcap_capture() -> Result<PcapHandle, String> {
+pub fn start_pcap_capture(_config: PcapCaptureConfig) -> Result<PcapHandle, String> {
     Err("pcap feature not enabled".to_string())
 }
 
@@ -285,15 +565,222 @@ struct TransportPacket<'a> {
     src_port: u16,
     dst_port: u16,
     proto: TransportProto,
-    payload: &'a [u8],
+    seq: u32,
+    flags: u8,
+    payload: Cow<'a, [u8]>,
+}
+
+#[cfg(feature = "pcap")]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct TcpFlowKey {
+    src_ip: IpAddr,
+    src_port: u16,
+    dst_ip: IpAddr,
+    dst_port: u16,
 }
 
 #[cfg(feature = "pcap")]
-fn handle_transport_packet(packet: TransportPacket<'_>, sender: &SyncSender<PcapMsg>) {
+struct TlsFlowBuffer {
+    data: Vec<u8>,
+    next_seq: u32,
+    last_seen: SystemTime,
+}
+
+#[cfg(feature = "pcap")]
+struct TlsReassembler {
+    flows: HashMap<TcpFlowKey, TlsFlowBuffer>,
+}
+
+#[cfg(feature = "pcap")]
+impl TlsReassembler {
+    fn new() -> Self {
+        Self {
+            flows: HashMap::new(),
+        }
+    }
+
+    // Feeds one in-order TCP payload into the per-flow buffer and returns a
+    // complete TLS record once enough bytes have accumulated. Returns None
+    // while the record is still incomplete (or the segment can't be placed).
+    fn push(&mut self, key: TcpFlowKey, seq: u32, flags: u8, payload: &[u8]) -> Option<Vec<u8>> {
+        let now = SystemTime::now();
+        self.evict_idle(now);
+
+        if flags & (TCP_FLAG_FIN | TCP_FLAG_RST) != 0 {
+            self.flows.remove(&key);
+            return None;
+        }
+        if payload.is_empty() {
+            return None;
+        }
+
+        match self.flows.get_mut(&key) {
+            None => {
+                if payload[0] != 0x16 {
+                    return None;
+                }
+                self.flows.insert(
+                    key,
+                    TlsFlowBuffer {
+                        data: payload.to_vec(),
+                        next_seq: seq.wrapping_add(payload.len() as u32),
+                        last_seen: now,
+                    },
+                );
+            }
+            Some(buf) => {
+                if buf.next_seq != seq {
+                    self.flows.remove(&key);
+                    return None;
+                }
+                buf.data.extend_from_slice(payload);
+                buf.next_seq = buf.next_seq.wrapping_add(payload.len() as u32);
+                buf.last_seen = now;
+            }
+        }
+
+        let buf = self.flows.get(&key)?;
+        if buf.data.len() > TLS_REASSEMBLY_MAX_BYTES {
+            self.flows.remove(&key);
+            return None;
+        }
+        if buf.data.len() < 5 {
+            return None;
+        }
+        let record_len = u16::from_be_bytes([buf.data[3], buf.data[4]]) as usize;
+        let total = 5 + record_len;
+        if buf.data.len() < total {
+            return None;
+        }
+
+        let buf = self.flows.remove(&key)?;
+        Some(buf.data)
+    }
+
+    fn evict_idle(&mut self, now: SystemTime) {
+        self.flows.retain(|_, buf| {
+            now.duration_since(buf.last_seen)
+                .map(|d| d.as_secs() < TLS_REASSEMBLY_IDLE_SECS)
+                .unwrap_or(true)
+        });
+    }
+}
+
+#[cfg(feature = "pcap")]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct FragKey {
+    src_ip: IpAddr,
+    dst_ip: IpAddr,
+    protocol: u8,
+    id: u32,
+}
+
+#[cfg(feature = "pcap")]
+struct FragBuffer {
+    parts: BTreeMap<usize, Vec<u8>>,
+    total_len: Option<usize>,
+    last_seen: SystemTime,
+}
+
+#[cfg(feature = "pcap")]
+struct IpReassembler {
+    flows: HashMap<FragKey, FragBuffer>,
+}
+
+#[cfg(feature = "pcap")]
+impl IpReassembler {
+    fn new() -> Self {
+        Self {
+            flows: HashMap::new(),
+        }
+    }
+
+    // Accumulates one fragment's payload (offset in bytes from the start of
+    // the transport payload) and returns the reassembled whole once a
+    // zero-offset fragment and a final (more_fragments == false) fragment
+    // are both present with no gaps between them.
+    fn push(
+        &mut self,
+        key: FragKey,
+        frag_offset: usize,
+        more_fragments: bool,
+        payload: &[u8],
+    ) -> Option<Vec<u8>> {
+        let now = SystemTime::now();
+        self.evict_idle(now);
+
+        let buf = self.flows.entry(key).or_insert_with(|| FragBuffer {
+            parts: BTreeMap::new(),
+            total_len: None,
+            last_seen: now,
+        });
+        buf.last_seen = now;
+        if !more_fragments {
+            buf.total_len = Some(frag_offset + payload.len());
+        }
+        buf.parts.insert(frag_offset, payload.to_vec());
+
+        let total = buf.total_len?;
+        let mut expected = 0usize;
+        for (&off, part) in buf.parts.iter() {
+            if off != expected {
+                return None;
+            }
+            expected += part.len();
+        }
+        if expected != total {
+            return None;
+        }
+
+        let buf = self.flows.remove(&key)?;
+        let mut out = Vec::with_capacity(total);
+        for (_, part) in buf.parts {
+            out.extend_from_slice(&part);
+        }
+        Some(out)
+    }
+
+    fn evict_idle(&mut self, now: SystemTime) {
+        self.flows.retain(|_, buf| {
+            now.duration_since(buf.last_seen)
+                .map(|d| d.as_secs() < IP_REASSEMBLY_IDLE_SECS)
+                .unwrap_or(true)
+        });
+    }
+}
+
+#[cfg(feature = "pcap")]
+struct CaptureState {
+    tls_reassembler: TlsReassembler,
+    ip_reassembler: IpReassembler,
+    verify_checksums: bool,
+    #[cfg(feature = "quic")]
+    quic_crypto: QuicCryptoReassembler,
+}
+
+#[cfg(feature = "pcap")]
+impl CaptureState {
+    fn new(verify_checksums: bool) -> Self {
+        Self {
+            tls_reassembler: TlsReassembler::new(),
+            ip_reassembler: IpReassembler::new(),
+            verify_checksums,
+            #[cfg(feature = "quic")]
+            quic_crypto: QuicCryptoReassembler::new(),
+        }
+    }
+}
+
+#[cfg(feature = "pcap")]
+fn handle_transport_packet(
+    packet: TransportPacket<'_>,
+    sender: &SyncSender<PcapMsg>,
+    state: &mut CaptureState,
+) {
     match packet.proto {
         TransportProto::Udp => {
             if packet.src_port == DNS_PORT || packet.dst_port == DNS_PORT {
-                if let Some((hostname, ips)) = parse_dns_packet(packet.payload, false) {
+                if let Some((hostname, ips)) = parse_dns_packet(&packet.payload, false) {
                     for ip in ips {
                         let _ = sender.try_send(PcapMsg::DnsMapping {
                             ip,
@@ -302,10 +789,37 @@ fn handle_transport_packet(packet: TransportPacket<'_>, sender: &SyncSender<Pcap
                     }
                 }
             }
+            if matches!(packet.src_port, DHCP_SERVER_PORT | DHCP_CLIENT_PORT)
+                || matches!(packet.dst_port, DHCP_SERVER_PORT | DHCP_CLIENT_PORT)
+            {
+                for (ip, role) in parse_dhcp_options(&packet.payload) {
+                    let _ = sender.try_send(PcapMsg::DhcpMapping { ip, role });
+                }
+            }
+            #[cfg(feature = "quic")]
+            if packet.dst_port == TLS_SNI_PORT {
+                if let Some((dcid, frames)) = parse_quic_initial(&packet.payload) {
+                    for (frame_offset, data) in frames {
+                        if let Some(client_hello) =
+                            state.quic_crypto.push(&dcid, frame_offset, &data)
+                        {
+                            if let Some(hostname) =
+                                parse_tls_sni(&wrap_as_tls_record(&client_hello))
+                            {
+                                let _ = sender.try_send(PcapMsg::SniMapping {
+                                    ip: packet.dst_ip,
+                                    port: packet.dst_port,
+                                    hostname,
+                                });
+                            }
+                        }
+                    }
+                }
+            }
         }
         TransportProto::Tcp => {
             if packet.src_port == DNS_PORT || packet.dst_port == DNS_PORT {
-                if let Some((hostname, ips)) = parse_dns_packet(packet.payload, true) {
+                if let Some((hostname, ips)) = parse_dns_packet(&packet.payload, true) {
                     for ip in ips {
                         let _ = sender.try_send(PcapMsg::DnsMapping {
                             ip,
@@ -315,12 +829,24 @@ fn handle_transport_packet(packet: TransportPacket<'_>, sender: &SyncSender<Pcap
                 }
             }
             if packet.dst_port == TLS_SNI_PORT {
-                if let Some(hostname) = parse_tls_sni(packet.payload) {
-                    let _ = sender.try_send(PcapMsg::SniMapping {
-                        ip: packet.dst_ip,
-                        port: packet.dst_port,
-                        hostname,
-                    });
+                let key = TcpFlowKey {
+                    src_ip: packet.src_ip,
+                    src_port: packet.src_port,
+                    dst_ip: packet.dst_ip,
+                    dst_port: packet.dst_port,
+                };
+                if let Some(record) =
+                    state
+                        .tls_reassembler
+                        .push(key, packet.seq, packet.flags, &packet.payload)
+                {
+                    if let Some(hostname) = parse_tls_sni(&record) {
+                        let _ = sender.try_send(PcapMsg::SniMapping {
+                            ip: packet.dst_ip,
+                            port: packet.dst_port,
+                            hostname,
+                        });
+                    }
                 }
             }
         }
@@ -328,7 +854,10 @@ fn handle_transport_packet(packet: TransportPacket<'_>, sender: &SyncSender<Pcap
 }
 
 #[cfg(feature = "pcap")]
-fn parse_transport_packet(data: &[u8]) -> Option<TransportPacket<'_>> {
+fn parse_transport_packet<'a>(
+    data: &'a [u8],
+    state: &mut CaptureState,
+) -> Option<TransportPacket<'a>> {
     if data.len() < 14 {
         return None;
     }
@@ -344,14 +873,18 @@ fn parse_transport_packet(data: &[u8]) -> Option<TransportPacket<'_>> {
     }
 
     match ethertype {
-        0x0800 => parse_ipv4_packet(data, offset),
-        0x86DD => parse_ipv6_packet(data, offset),
+        0x0800 => parse_ipv4_packet(data, offset, state),
+        0x86DD => parse_ipv6_packet(data, offset, state),
         _ => None,
     }
 }
 
 #[cfg(feature = "pcap")]
-fn parse_ipv4_packet(data: &[u8], offset: usize) -> Option<TransportPacket<'_>> {
+fn parse_ipv4_packet<'a>(
+    data: &'a [u8],
+    offset: usize,
+    state: &mut CaptureState,
+) -> Option<TransportPacket<'a>> {
     if data.len() < offset + 20 {
         return None;
     }
@@ -359,7 +892,11 @@ fn parse_ipv4_packet(data: &[u8], offset: usize) -> Option<TransportPacket<'_>>
     if ihl < 20 || data.len() < offset + ihl {
         return None;
     }
+    if state.verify_checksums && !ipv4_header_checksum_ok(data, offset, ihl) {
+        return None;
+    }
     let proto = data[offset + 9];
+    let total_length = u16::from_be_bytes([data[offset + 2], data[offset + 3]]) as usize;
     let src_ip = IpAddr::V4(Ipv4Addr::new(
         data[offset + 12],
         data[offset + 13],
@@ -373,28 +910,353 @@ fn parse_ipv4_packet(data: &[u8], offset: usize) -> Option<TransportPacket<'_>>
         data[offset + 19],
     ));
     let l4_offset = offset + ihl;
+
+    let flags_frag = u16::from_be_bytes([data[offset + 6], data[offset + 7]]);
+    let more_fragments = flags_frag & 0x2000 != 0;
+    let frag_offset = (flags_frag & 0x1FFF) as usize * 8;
+
+    if more_fragments || frag_offset != 0 {
+        let identification = u16::from_be_bytes([data[offset + 4], data[offset + 5]]);
+        let payload_end = (offset + total_length).min(data.len());
+        if payload_end < l4_offset {
+            return None;
+        }
+        let payload = &data[l4_offset..payload_end];
+        let key = FragKey {
+            src_ip,
+            dst_ip,
+            protocol: proto,
+            id: identification as u32,
+        };
+        let reassembled = state
+            .ip_reassembler
+            .push(key, frag_offset, more_fragments, payload)?;
+        return transport_packet_from_reassembled(
+            proto,
+            reassembled,
+            src_ip,
+            dst_ip,
+            state.verify_checksums,
+        );
+    }
+
+    // Bound the slice handed to the transport parsers to the datagram's real
+    // extent (per the IPv4 total-length field), not the whole captured
+    // frame, so L2 padding on short packets can't inflate the pseudo-header
+    // length and desync the checksum (mirrors the fragmented branch above).
+    let packet_end = (offset + total_length).min(data.len());
+    if packet_end < l4_offset {
+        return None;
+    }
+    let bounded = &data[..packet_end];
+
     match proto {
-        6 => parse_tcp_segment(data, l4_offset, src_ip, dst_ip),
-        17 => parse_udp_datagram(data, l4_offset, src_ip, dst_ip),
+        6 => parse_tcp_segment(bounded, l4_offset, src_ip, dst_ip, state.verify_checksums),
+        17 => parse_udp_datagram(bounded, l4_offset, src_ip, dst_ip, state.verify_checksums),
         _ => None,
     }
 }
 
 #[cfg(feature = "pcap")]
-fn parse_ipv6_packet(data: &[u8], offset: usize) -> Option<TransportPacket<'_>> {
+fn parse_ipv6_packet<'a>(
+    data: &'a [u8],
+    offset: usize,
+    state: &mut CaptureState,
+) -> Option<TransportPacket<'a>> {
     if data.len() < offset + 40 {
         return None;
     }
-    let next_header = data[offset + 6];
+    let mut next_header = data[offset + 6];
+    // Payload Length covers everything after this 40-byte fixed header
+    // (extension headers + upper-layer payload), so it bounds the slice
+    // handed to the TCP/UDP parsers the same way IPv4's total-length field
+    // does, preventing L2 padding on short packets from desyncing the
+    // checksum pseudo-header.
+    let payload_length = u16::from_be_bytes([data[offset + 4], data[offset + 5]]) as usize;
+    let packet_end = (offset + 40 + payload_length).min(data.len());
     let src_bytes: [u8; 16] = data[offset + 8..offset + 24].try_into().ok()?;
     let src_ip = IpAddr::V6(Ipv6Addr::from(src_bytes));
     let dst_bytes: [u8; 16] = data[offset + 24..offset + 40].try_into().ok()?;
     let dst_ip = IpAddr::V6(Ipv6Addr::from(dst_bytes));
-    let l4_offset = offset + 40;
-    match next_header {
-        6 => parse_tcp_segment(data, l4_offset, src_ip, dst_ip),
-        17 => parse_udp_datagram(data, l4_offset, src_ip, dst_ip),
-        _ => None,
+    let mut cursor = offset + 40;
+
+    for _ in 0..IPV6_MAX_EXT_HEADERS {
+        match next_header {
+            IPV6_NH_TCP => {
+                if cursor > packet_end {
+                    return None;
+                }
+                let bounded = &data[..packet_end];
+                return parse_tcp_segment(bounded, cursor, src_ip, dst_ip, state.verify_checksums);
+            }
+            IPV6_NH_UDP => {
+                if cursor > packet_end {
+                    return None;
+                }
+                let bounded = &data[..packet_end];
+                return parse_udp_datagram(bounded, cursor, src_ip, dst_ip, state.verify_checksums);
+            }
+            IPV6_NH_FRAGMENT => return parse_ipv6_fragment(data, cursor, src_ip, dst_ip, state),
+            IPV6_NH_HOP_BY_HOP | IPV6_NH_ROUTING | IPV6_NH_DEST_OPTIONS => {
+                if data.len() < cursor + 2 {
+                    return None;
+                }
+                let hdr_next = data[cursor];
+                let hdr_ext_len = data[cursor + 1] as usize;
+                let hdr_len = (hdr_ext_len + 1) * 8;
+                if data.len() < cursor + hdr_len {
+                    return None;
+                }
+                next_header = hdr_next;
+                cursor += hdr_len;
+            }
+            IPV6_NH_NO_NEXT_HEADER => return None,
+            _ => return None,
+        }
+    }
+    None
+}
+
+// Parses an IPv6 Fragment extension header (next-header 44) and feeds the
+// remaining payload into the shared IP reassembler.
+#[cfg(feature = "pcap")]
+fn parse_ipv6_fragment<'a>(
+    data: &'a [u8],
+    offset: usize,
+    src_ip: IpAddr,
+    dst_ip: IpAddr,
+    state: &mut CaptureState,
+) -> Option<TransportPacket<'a>> {
+    if data.len() < offset + 8 {
+        return None;
+    }
+    let next_header = data[offset];
+    let frag_word = u16::from_be_bytes([data[offset + 2], data[offset + 3]]);
+    let more_fragments = frag_word & 0x1 != 0;
+    let frag_offset = (frag_word >> 3) as usize * 8;
+    let identification = u32::from_be_bytes([
+        data[offset + 4],
+        data[offset + 5],
+        data[offset + 6],
+        data[offset + 7],
+    ]);
+    if next_header != 6 && next_header != 17 {
+        return None;
+    }
+    let payload = &data[offset + 8..];
+    let key = FragKey {
+        src_ip,
+        dst_ip,
+        protocol: next_header,
+        id: identification,
+    };
+    let reassembled = state
+        .ip_reassembler
+        .push(key, frag_offset, more_fragments, payload)?;
+    transport_packet_from_reassembled(
+        next_header,
+        reassembled,
+        src_ip,
+        dst_ip,
+        state.verify_checksums,
+    )
+}
+
+// Parses a UDP header starting at byte 0 of `seg` and returns
+// (src_port, dst_port, payload_offset).
+#[cfg(feature = "pcap")]
+fn parse_udp_header(seg: &[u8]) -> Option<(u16, u16, usize)> {
+    if seg.len() < 8 {
+        return None;
+    }
+    let src_port = u16::from_be_bytes([seg[0], seg[1]]);
+    let dst_port = u16::from_be_bytes([seg[2], seg[3]]);
+    Some((src_port, dst_port, 8))
+}
+
+// Parses a TCP header starting at byte 0 of `seg` and returns
+// (src_port, dst_port, seq, flags, payload_offset).
+#[cfg(feature = "pcap")]
+fn parse_tcp_header(seg: &[u8]) -> Option<(u16, u16, u32, u8, usize)> {
+    if seg.len() < 20 {
+        return None;
+    }
+    let src_port = u16::from_be_bytes([seg[0], seg[1]]);
+    let dst_port = u16::from_be_bytes([seg[2], seg[3]]);
+    let seq = u32::from_be_bytes([seg[4], seg[5], seg[6], seg[7]]);
+    let flags = seg[13];
+    let data_offset = (seg[12] >> 4) as usize * 4;
+    if data_offset < 20 || seg.len() < data_offset {
+        return None;
+    }
+    Some((src_port, dst_port, seq, flags, data_offset))
+}
+
+// RFC 1071 internet checksum over `bytes`. A transmitted checksum validates
+// when this returns 0 (the field itself is included in the input).
+#[cfg(feature = "pcap")]
+fn internet_checksum(bytes: &[u8]) -> u16 {
+    let mut sum: u32 = 0;
+    for chunk in bytes.chunks(2) {
+        let word = if chunk.len() == 2 {
+            u16::from_be_bytes([chunk[0], chunk[1]])
+        } else {
+            u16::from_be_bytes([chunk[0], 0])
+        };
+        sum += word as u32;
+    }
+    while sum >> 16 != 0 {
+        sum = (sum & 0xFFFF) + (sum >> 16);
+    }
+    !(sum as u16)
+}
+
+#[cfg(feature = "pcap")]
+fn ipv4_header_checksum_ok(data: &[u8], offset: usize, ihl: usize) -> bool {
+    let checksum_field = u16::from_be_bytes([data[offset + 10], data[offset + 11]]);
+    if checksum_field == 0 {
+        return true;
+    }
+    internet_checksum(&data[offset..offset + ihl]) == 0
+}
+
+// Validates a TCP/UDP checksum against the IPv4/IPv6 pseudo-header. A zeroed
+// checksum field is treated as "not computed" (common with checksum
+// offload) rather than invalid.
+#[cfg(feature = "pcap")]
+fn transport_checksum_ok(
+    protocol: u8,
+    src_ip: IpAddr,
+    dst_ip: IpAddr,
+    segment: &[u8],
+    checksum_offset: usize,
+) -> bool {
+    if segment.len() < checksum_offset + 2 {
+        return false;
+    }
+    let checksum_field =
+        u16::from_be_bytes([segment[checksum_offset], segment[checksum_offset + 1]]);
+    if checksum_field == 0 {
+        return true;
+    }
+
+    let mut pseudo = Vec::with_capacity(40 + segment.len());
+    match (src_ip, dst_ip) {
+        (IpAddr::V4(src), IpAddr::V4(dst)) => {
+            pseudo.extend_from_slice(&src.octets());
+            pseudo.extend_from_slice(&dst.octets());
+            pseudo.push(0);
+            pseudo.push(protocol);
+            pseudo.extend_from_slice(&(segment.len() as u16).to_be_bytes());
+        }
+        (IpAddr::V6(src), IpAddr::V6(dst)) => {
+            pseudo.extend_from_slice(&src.octets());
+            pseudo.extend_from_slice(&dst.octets());
+            pseudo.extend_from_slice(&(segment.len() as u32).to_be_bytes());
+            pseudo.extend_from_slice(&[0, 0, 0]);
+            pseudo.push(protocol);
+        }
+        _ => return true,
+    }
+    pseudo.extend_from_slice(segment);
+    internet_checksum(&pseudo) == 0
+}
+
+// Checksum vectors below are hand-built: a 20-byte IPv4 header plus an
+// 8-byte UDP header + payload with correctly computed checksums, so tests
+// pin exact accept/reject behavior rather than just checking it runs.
+#[cfg(all(test, feature = "pcap"))]
+mod checksum_tests {
+    use super::*;
+
+    // src 192.168.1.10 -> dst 192.168.1.1, proto UDP, total_length 33,
+    // UDP src port 5000 -> dst port 53, payload b"hello".
+    const IPV4_UDP_PACKET: [u8; 33] = [
+        0x45, 0x00, 0x00, 0x21, 0x12, 0x34, 0x00, 0x00, 0x40, 0x11, 0xe5, 0x3c, 0xc0, 0xa8, 0x01,
+        0x0a, 0xc0, 0xa8, 0x01, 0x01, 0x13, 0x88, 0x00, 0x35, 0x00, 0x0d, 0x24, 0xe9, 0x68, 0x65,
+        0x6c, 0x6c, 0x6f,
+    ];
+    const SRC_IP: IpAddr = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 10));
+    const DST_IP: IpAddr = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1));
+
+    #[test]
+    fn ipv4_header_checksum_ok_accepts_valid_header() {
+        assert!(ipv4_header_checksum_ok(&IPV4_UDP_PACKET, 0, 20));
+    }
+
+    #[test]
+    fn ipv4_header_checksum_ok_rejects_corrupted_header() {
+        let mut corrupted = IPV4_UDP_PACKET;
+        corrupted[8] = 63; // flip the TTL byte, leaving the checksum stale
+        assert!(!ipv4_header_checksum_ok(&corrupted, 0, 20));
+    }
+
+    #[test]
+    fn ipv4_header_checksum_ok_treats_zeroed_field_as_offloaded() {
+        let mut zeroed = IPV4_UDP_PACKET;
+        zeroed[10] = 0;
+        zeroed[11] = 0;
+        assert!(ipv4_header_checksum_ok(&zeroed, 0, 20));
+    }
+
+    #[test]
+    fn transport_checksum_ok_accepts_valid_udp_segment() {
+        let udp_segment = &IPV4_UDP_PACKET[20..];
+        assert!(transport_checksum_ok(17, SRC_IP, DST_IP, udp_segment, 6));
+    }
+
+    #[test]
+    fn transport_checksum_ok_rejects_corrupted_udp_payload() {
+        let mut corrupted = IPV4_UDP_PACKET;
+        corrupted[32] ^= 0xff; // flip a payload byte, leaving the checksum stale
+        assert!(!transport_checksum_ok(
+            17,
+            SRC_IP,
+            DST_IP,
+            &corrupted[20..],
+            6
+        ));
+    }
+
+    #[test]
+    fn transport_checksum_ok_treats_zeroed_field_as_offloaded() {
+        let mut zeroed = IPV4_UDP_PACKET;
+        // UDP checksum field is segment bytes 6-7, i.e. full-packet bytes 26/27
+        // (20-byte IP header + 6), not 24/25 (that's the UDP length field).
+        zeroed[26] = 0;
+        zeroed[27] = 0;
+        assert!(transport_checksum_ok(17, SRC_IP, DST_IP, &zeroed[20..], 6));
+    }
+
+    // Trailing L2 padding (e.g. Ethernet's 60-byte minimum frame size) must
+    // not be folded into the pseudo-header length: parse_ipv4_packet has to
+    // bound the transport parsers to the IPv4 total-length field, not the
+    // whole captured frame.
+    #[test]
+    fn parse_ipv4_packet_ignores_trailing_l2_padding() {
+        let mut padded = IPV4_UDP_PACKET.to_vec();
+        padded.extend_from_slice(&[0u8; 20]);
+        let mut state = CaptureState::new(true);
+        let packet = parse_ipv4_packet(&padded, 0, &mut state).expect("valid padded packet");
+        assert_eq!(packet.payload.as_ref(), b"hello");
+    }
+
+    // Same padding hazard as above, but for the IPv6 payload-length field
+    // instead of IPv4's total-length field.
+    #[test]
+    fn parse_ipv6_packet_ignores_trailing_l2_padding() {
+        // 2001:db8::1 -> 2001:db8::2, next-header UDP, UDP src 5000 -> dst 53, payload b"hi".
+        const IPV6_UDP_PACKET: [u8; 50] = [
+            0x60, 0x00, 0x00, 0x00, 0x00, 0x0a, 0x11, 0x40, 0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x20, 0x01, 0x0d, 0xb8,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x13, 0x88,
+            0x00, 0x35, 0x00, 0x0a, 0x28, 0x3f, 0x68, 0x69,
+        ];
+        let mut padded = IPV6_UDP_PACKET.to_vec();
+        padded.extend_from_slice(&[0u8; 20]);
+        let mut state = CaptureState::new(true);
+        let packet = parse_ipv6_packet(&padded, 0, &mut state).expect("valid padded packet");
+        assert_eq!(packet.payload.as_ref(), b"hi");
     }
 }
 
@@ -404,20 +1266,23 @@ fn parse_udp_datagram(
     offset: usize,
     src_ip: IpAddr,
     dst_ip: IpAddr,
+    verify_checksums: bool,
 ) -> Option<TransportPacket<'_>> {
-    if data.len() < offset + 8 {
+    let segment = &data[offset..];
+    let (src_port, dst_port, payload_offset) = parse_udp_header(segment)?;
+    if verify_checksums && !transport_checksum_ok(17, src_ip, dst_ip, segment, 6) {
         return None;
     }
-    let src_port = u16::from_be_bytes([data[offset], data[offset + 1]]);
-    let dst_port = u16::from_be_bytes([data[offset + 2], data[offset + 3]]);
-    let payload = &data[offset + 8..];
+    let payload = &segment[payload_offset..];
     Some(TransportPacket {
         src_ip,
         dst_ip,
         src_port,
         dst_port,
         proto: TransportProto::Udp,
-        payload,
+        seq: 0,
+        flags: 0,
+        payload: Cow::Borrowed(payload),
     })
 }
 
@@ -427,27 +1292,76 @@ fn parse_tcp_segment(
     offset: usize,
     src_ip: IpAddr,
     dst_ip: IpAddr,
+    verify_checksums: bool,
 ) -> Option<TransportPacket<'_>> {
-    if data.len() < offset + 20 {
-        return None;
-    }
-    let src_port = u16::from_be_bytes([data[offset], data[offset + 1]]);
-    let dst_port = u16::from_be_bytes([data[offset + 2], data[offset + 3]]);
-    let data_offset = (data[offset + 12] >> 4) as usize * 4;
-    if data_offset < 20 || data.len() < offset + data_offset {
+    let segment = &data[offset..];
+    let (src_port, dst_port, seq, flags, payload_offset) = parse_tcp_header(segment)?;
+    if verify_checksums && !transport_checksum_ok(6, src_ip, dst_ip, segment, 16) {
         return None;
     }
-    let payload = &data[offset + data_offset..];
+    let payload = &segment[payload_offset..];
     Some(TransportPacket {
         src_ip,
         dst_ip,
         src_port,
         dst_port,
         proto: TransportProto::Tcp,
-        payload,
+        seq,
+        flags,
+        payload: Cow::Borrowed(payload),
     })
 }
 
+// Builds a TransportPacket from a reassembled (owned) datagram/segment, used
+// once IP-fragment reassembly has produced a contiguous payload.
+#[cfg(feature = "pcap")]
+fn transport_packet_from_reassembled(
+    protocol: u8,
+    reassembled: Vec<u8>,
+    src_ip: IpAddr,
+    dst_ip: IpAddr,
+    verify_checksums: bool,
+) -> Option<TransportPacket<'static>> {
+    match protocol {
+        6 => {
+            let (src_port, dst_port, seq, flags, payload_offset) =
+                parse_tcp_header(&reassembled)?;
+            if verify_checksums && !transport_checksum_ok(6, src_ip, dst_ip, &reassembled, 16) {
+                return None;
+            }
+            let payload = reassembled[payload_offset..].to_vec();
+            Some(TransportPacket {
+                src_ip,
+                dst_ip,
+                src_port,
+                dst_port,
+                proto: TransportProto::Tcp,
+                seq,
+                flags,
+                payload: Cow::Owned(payload),
+            })
+        }
+        17 => {
+            let (src_port, dst_port, payload_offset) = parse_udp_header(&reassembled)?;
+            if verify_checksums && !transport_checksum_ok(17, src_ip, dst_ip, &reassembled, 6) {
+                return None;
+            }
+            let payload = reassembled[payload_offset..].to_vec();
+            Some(TransportPacket {
+                src_ip,
+                dst_ip,
+                src_port,
+                dst_port,
+                proto: TransportProto::Udp,
+                seq: 0,
+                flags: 0,
+                payload: Cow::Owned(payload),
+            })
+        }
+        _ => None,
+    }
+}
+
 #[cfg(feature = "pcap")]
 fn parse_dns_packet(payload: &[u8], tcp: bool) -> Option<(String, Vec<IpAddr>)> {
     let data = if tcp {
@@ -575,6 +1489,57 @@ fn parse_dns_name(packet: &[u8], offset: &mut usize, depth: usize) -> Option<Str
     Some(labels.join("."))
 }
 
+// Walks a BOOTP/DHCP message's options and returns the router (option 3)
+// and DNS server (option 6) addresses it carries. The subnet mask (option 1)
+// is recognized but doesn't map to an `InfraRole`, so it's skipped.
+#[cfg(feature = "pcap")]
+fn parse_dhcp_options(payload: &[u8]) -> Vec<(IpAddr, InfraRole)> {
+    let mut results = Vec::new();
+    if payload.len() < 240 || payload[236..240] != DHCP_MAGIC_COOKIE {
+        return results;
+    }
+
+    let mut pos = 240;
+    while pos < payload.len() {
+        let code = payload[pos];
+        if code == 0 {
+            pos += 1;
+            continue;
+        }
+        if code == DHCP_OPT_END {
+            break;
+        }
+        if pos + 1 >= payload.len() {
+            break;
+        }
+        let len = payload[pos + 1] as usize;
+        pos += 2;
+        if payload.len() < pos + len {
+            break;
+        }
+        let data = &payload[pos..pos + len];
+
+        let role = match code {
+            DHCP_OPT_ROUTER => Some(InfraRole::Gateway),
+            DHCP_OPT_DNS_SERVER => Some(InfraRole::Resolver),
+            DHCP_OPT_SUBNET_MASK => None,
+            _ => None,
+        };
+        if let Some(role) = role {
+            for chunk in data.chunks(4) {
+                if chunk.len() == 4 {
+                    let ip = IpAddr::V4(Ipv4Addr::new(chunk[0], chunk[1], chunk[2], chunk[3]));
+                    results.push((ip, role));
+                }
+            }
+        }
+
+        pos += len;
+    }
+
+    results
+}
+
 #[cfg(feature = "pcap")]
 fn parse_tls_sni(payload: &[u8]) -> Option<String> {
     if payload.len() < 5 {
@@ -683,3 +1648,391 @@ fn parse_tls_sni(payload: &[u8]) -> Option<String> {
     }
     None
 }
+
+// QUIC Initial decryption, gated behind the `quic` feature (and the
+// `hkdf`/`sha2`/`aes`/`aes-gcm` crates it pulls in) since it's meaningfully
+// heavier than the rest of this module's parsing.
+#[cfg(all(feature = "pcap", feature = "quic"))]
+struct QuicCryptoBuffer {
+    parts: BTreeMap<usize, Vec<u8>>,
+    last_seen: SystemTime,
+}
+
+#[cfg(all(feature = "pcap", feature = "quic"))]
+struct QuicCryptoReassembler {
+    flows: HashMap<Vec<u8>, QuicCryptoBuffer>,
+}
+
+#[cfg(all(feature = "pcap", feature = "quic"))]
+impl QuicCryptoReassembler {
+    fn new() -> Self {
+        Self {
+            flows: HashMap::new(),
+        }
+    }
+
+    // Accumulates CRYPTO frame data (keyed by the connection's DCID) and
+    // returns the reassembled TLS Handshake message once a zero-offset
+    // fragment is present and its own length field is satisfied.
+    fn push(&mut self, dcid: &[u8], frame_offset: usize, data: &[u8]) -> Option<Vec<u8>> {
+        let now = SystemTime::now();
+        self.evict_idle(now);
+
+        let buf = self.flows.entry(dcid.to_vec()).or_insert_with(|| QuicCryptoBuffer {
+            parts: BTreeMap::new(),
+            last_seen: now,
+        });
+        buf.last_seen = now;
+        buf.parts.insert(frame_offset, data.to_vec());
+
+        let total_buffered: usize = buf.parts.values().map(Vec::len).sum();
+        if total_buffered > QUIC_CRYPTO_MAX_BYTES {
+            self.flows.remove(dcid);
+            return None;
+        }
+
+        let buf = self.flows.get(dcid)?;
+        let mut contiguous = Vec::with_capacity(total_buffered);
+        let mut expected = 0usize;
+        for (&off, part) in buf.parts.iter() {
+            if off != expected {
+                return None;
+            }
+            contiguous.extend_from_slice(part);
+            expected += part.len();
+        }
+
+        if contiguous.len() < 4 || contiguous[0] != 0x01 {
+            self.flows.remove(dcid);
+            return None;
+        }
+        let hs_len = ((contiguous[1] as usize) << 16)
+            | ((contiguous[2] as usize) << 8)
+            | (contiguous[3] as usize);
+        let total = 4 + hs_len;
+        if contiguous.len() < total {
+            return None;
+        }
+
+        self.flows.remove(dcid);
+        contiguous.truncate(total);
+        Some(contiguous)
+    }
+
+    fn evict_idle(&mut self, now: SystemTime) {
+        self.flows.retain(|_, buf| {
+            now.duration_since(buf.last_seen)
+                .map(|d| d.as_secs() < QUIC_CRYPTO_IDLE_SECS)
+                .unwrap_or(true)
+        });
+    }
+}
+
+#[cfg(all(feature = "pcap", feature = "quic"))]
+fn read_quic_varint(data: &[u8], pos: &mut usize) -> Option<u64> {
+    let first = *data.get(*pos)?;
+    let len = 1usize << (first >> 6);
+    if data.len() < *pos + len {
+        return None;
+    }
+    let mut value = (first & 0x3f) as u64;
+    for i in 1..len {
+        value = (value << 8) | data[*pos + i] as u64;
+    }
+    *pos += len;
+    Some(value)
+}
+
+#[cfg(all(feature = "pcap", feature = "quic"))]
+fn hkdf_expand_label(prk: &[u8], label: &str, length: usize) -> Option<Vec<u8>> {
+    use hkdf::Hkdf;
+    use sha2::Sha256;
+
+    let hkdf = Hkdf::<Sha256>::from_prk(prk).ok()?;
+    let full_label = format!("tls13 {label}");
+    let mut info = Vec::with_capacity(3 + full_label.len());
+    info.extend_from_slice(&(length as u16).to_be_bytes());
+    info.push(full_label.len() as u8);
+    info.extend_from_slice(full_label.as_bytes());
+    info.push(0);
+
+    let mut out = vec![0u8; length];
+    hkdf.expand(&info, &mut out).ok()?;
+    Some(out)
+}
+
+#[cfg(all(feature = "pcap", feature = "quic"))]
+fn quic_initial_secrets(dcid: &[u8]) -> Option<([u8; 16], [u8; 12], [u8; 16])> {
+    use hkdf::Hkdf;
+    use sha2::Sha256;
+
+    let (initial_secret, _) = Hkdf::<Sha256>::extract(Some(&QUIC_V1_INITIAL_SALT), dcid);
+    let client_secret = hkdf_expand_label(initial_secret.as_slice(), "client in", 32)?;
+    let key = hkdf_expand_label(&client_secret, "quic key", 16)?;
+    let iv = hkdf_expand_label(&client_secret, "quic iv", 12)?;
+    let hp = hkdf_expand_label(&client_secret, "quic hp", 16)?;
+
+    Some((key.try_into().ok()?, iv.try_into().ok()?, hp.try_into().ok()?))
+}
+
+#[cfg(all(feature = "pcap", feature = "quic"))]
+fn quic_header_protection_mask(hp_key: &[u8; 16], sample: &[u8]) -> [u8; 16] {
+    use aes::cipher::{generic_array::GenericArray, BlockEncrypt, KeyInit};
+    use aes::Aes128;
+
+    let cipher = Aes128::new(GenericArray::from_slice(hp_key));
+    let mut block = GenericArray::clone_from_slice(sample);
+    cipher.encrypt_block(&mut block);
+    block.into()
+}
+
+#[cfg(all(feature = "pcap", feature = "quic"))]
+fn quic_aead_decrypt(
+    key: &[u8; 16],
+    nonce: &[u8; 12],
+    aad: &[u8],
+    ciphertext_and_tag: &[u8],
+) -> Option<Vec<u8>> {
+    use aes_gcm::aead::{Aead, KeyInit, Payload};
+    use aes_gcm::{Aes128Gcm, Key, Nonce};
+
+    let cipher = Aes128Gcm::new(Key::<Aes128Gcm>::from_slice(key));
+    cipher
+        .decrypt(
+            Nonce::from_slice(nonce),
+            Payload {
+                msg: ciphertext_and_tag,
+                aad,
+            },
+        )
+        .ok()
+}
+
+// (byte offset within the CRYPTO stream, frame data) pairs pulled out of a
+// decrypted Initial packet.
+#[cfg(all(feature = "pcap", feature = "quic"))]
+type CryptoFrames = Vec<(usize, Vec<u8>)>;
+
+// Splits a decrypted Initial packet's plaintext into its CRYPTO frames
+// (type 0x06), skipping PADDING (0x00). Bails at the first frame type it
+// doesn't recognize rather than implementing a full frame parser, since a
+// client's first flight is PADDING + CRYPTO (+ maybe PING/ACK, which we
+// don't need for SNI extraction).
+#[cfg(all(feature = "pcap", feature = "quic"))]
+fn quic_extract_crypto_frames(plaintext: &[u8]) -> CryptoFrames {
+    let mut frames = Vec::new();
+    let mut pos = 0;
+    while pos < plaintext.len() {
+        match plaintext[pos] {
+            0x00 => pos += 1,
+            0x06 => {
+                pos += 1;
+                let Some(offset) = read_quic_varint(plaintext, &mut pos) else {
+                    break;
+                };
+                let Some(len) = read_quic_varint(plaintext, &mut pos) else {
+                    break;
+                };
+                let len = len as usize;
+                if plaintext.len() < pos + len {
+                    break;
+                }
+                frames.push((offset as usize, plaintext[pos..pos + len].to_vec()));
+                pos += len;
+            }
+            _ => break,
+        }
+    }
+    frames
+}
+
+// Detects and decrypts a QUIC v1 Initial packet, returning its DCID and the
+// CRYPTO frames carried inside (still possibly a fragment of the full
+// ClientHello, to be reassembled by `QuicCryptoReassembler`).
+#[cfg(all(feature = "pcap", feature = "quic"))]
+fn parse_quic_initial(payload: &[u8]) -> Option<(Vec<u8>, CryptoFrames)> {
+    if payload.len() < 7 {
+        return None;
+    }
+    let first_byte = payload[0];
+    if first_byte & 0x80 == 0 {
+        return None;
+    }
+    let version = u32::from_be_bytes([payload[1], payload[2], payload[3], payload[4]]);
+    if version != QUIC_VERSION_1 {
+        return None;
+    }
+    if first_byte & 0x30 != 0x00 {
+        return None;
+    }
+
+    let mut pos = 5;
+    let dcid_len = *payload.get(pos)? as usize;
+    pos += 1;
+    if payload.len() < pos + dcid_len {
+        return None;
+    }
+    let dcid = payload[pos..pos + dcid_len].to_vec();
+    pos += dcid_len;
+
+    let scid_len = *payload.get(pos)? as usize;
+    pos += 1;
+    if payload.len() < pos + scid_len {
+        return None;
+    }
+    pos += scid_len;
+
+    let token_len = read_quic_varint(payload, &mut pos)? as usize;
+    if payload.len() < pos + token_len {
+        return None;
+    }
+    pos += token_len;
+
+    let length = read_quic_varint(payload, &mut pos)? as usize;
+    let pn_offset = pos;
+    let packet_end = (pn_offset + length).min(payload.len());
+    if packet_end <= pn_offset || payload.len() < pn_offset + 4 + 16 {
+        return None;
+    }
+
+    let (key, iv, hp) = quic_initial_secrets(&dcid)?;
+    let sample_start = pn_offset + 4;
+    let sample = &payload[sample_start..sample_start + 16];
+    let mask = quic_header_protection_mask(&hp, sample);
+
+    let mut header = payload[..pn_offset].to_vec();
+    header[0] ^= mask[0] & 0x0f;
+    let pn_len = (header[0] & 0x03) as usize + 1;
+    if payload.len() < pn_offset + pn_len {
+        return None;
+    }
+
+    let mut pn_bytes = payload[pn_offset..pn_offset + pn_len].to_vec();
+    for (i, byte) in pn_bytes.iter_mut().enumerate() {
+        *byte ^= mask[1 + i];
+    }
+    header.extend_from_slice(&pn_bytes);
+
+    let mut packet_number: u64 = 0;
+    for byte in &pn_bytes {
+        packet_number = (packet_number << 8) | *byte as u64;
+    }
+
+    let mut nonce = iv;
+    let pn_be = packet_number.to_be_bytes();
+    for i in 0..8 {
+        nonce[4 + i] ^= pn_be[i];
+    }
+
+    let ciphertext_start = pn_offset + pn_len;
+    if packet_end < ciphertext_start + 16 {
+        return None;
+    }
+    let ciphertext_and_tag = &payload[ciphertext_start..packet_end];
+    let plaintext = quic_aead_decrypt(&key, &nonce, &header, ciphertext_and_tag)?;
+
+    let frames = quic_extract_crypto_frames(&plaintext);
+    if frames.is_empty() {
+        return None;
+    }
+    Some((dcid, frames))
+}
+
+// Wraps a raw TLS Handshake message (as carried in QUIC CRYPTO frames,
+// without a TLS record layer) in a synthetic record header so it can be
+// fed straight into `parse_tls_sni`.
+#[cfg(all(feature = "pcap", feature = "quic"))]
+fn wrap_as_tls_record(handshake: &[u8]) -> Vec<u8> {
+    let mut record = Vec::with_capacity(5 + handshake.len());
+    record.push(0x16);
+    record.extend_from_slice(&[0x03, 0x03]);
+    record.extend_from_slice(&(handshake.len() as u16).to_be_bytes());
+    record.extend_from_slice(handshake);
+    record
+}
+
+// The derived client key/iv/hp below are from RFC 9001 Appendix A.1 and the
+// AEAD header (AAD) is the one from Appendix A.2; the header-protection
+// sample and the AEAD ciphertext are computed from those keys. Together they
+// pin exact byte output of the HKDF/AES-GCM chain rather than just checking
+// that it runs.
+#[cfg(all(test, feature = "pcap", feature = "quic"))]
+mod quic_tests {
+    use super::*;
+
+    // RFC 9001 Appendix A.1: client-chosen DCID and the initial secrets it
+    // derives for QUIC v1.
+    const RFC9001_DCID: [u8; 8] = [0x83, 0x94, 0xc8, 0xf0, 0x3e, 0x51, 0x57, 0x08];
+    const RFC9001_CLIENT_KEY: [u8; 16] = [
+        0x1f, 0x36, 0x96, 0x13, 0xdd, 0x76, 0xd5, 0x46, 0x77, 0x30, 0xef, 0xcb, 0xe3, 0xb1, 0xa2,
+        0x2d,
+    ];
+    const RFC9001_CLIENT_IV: [u8; 12] = [
+        0xfa, 0x04, 0x4b, 0x2f, 0x42, 0xa3, 0xfd, 0x3b, 0x46, 0xfb, 0x25, 0x5c,
+    ];
+    const RFC9001_CLIENT_HP: [u8; 16] = [
+        0x9f, 0x50, 0x44, 0x9e, 0x04, 0xa0, 0xe8, 0x10, 0x28, 0x3a, 0x1e, 0x99, 0x33, 0xad, 0xed,
+        0xd2,
+    ];
+
+    #[test]
+    fn quic_initial_secrets_match_rfc9001_vectors() {
+        let (key, iv, hp) = quic_initial_secrets(&RFC9001_DCID).unwrap();
+        assert_eq!(key, RFC9001_CLIENT_KEY);
+        assert_eq!(iv, RFC9001_CLIENT_IV);
+        assert_eq!(hp, RFC9001_CLIENT_HP);
+    }
+
+    #[test]
+    fn quic_header_protection_mask_matches_known_vector() {
+        let sample = [
+            0xbf, 0xd4, 0xe5, 0x10, 0xb9, 0x48, 0xee, 0x20, 0x86, 0x71, 0x19, 0xd9, 0x39, 0x0c,
+            0x5d, 0xc6,
+        ];
+        let mask = quic_header_protection_mask(&RFC9001_CLIENT_HP, &sample);
+        let expected = [
+            0x38, 0x9a, 0xb9, 0x3d, 0xaa, 0xd2, 0xab, 0xc8, 0x92, 0xec, 0x47, 0xe0, 0x34, 0x8a,
+            0xcc, 0x63,
+        ];
+        assert_eq!(mask, expected);
+    }
+
+    #[test]
+    fn quic_aead_decrypt_round_trip() {
+        let nonce = [
+            0xfa, 0x04, 0x4b, 0x2f, 0x42, 0xa3, 0xfd, 0x3b, 0x46, 0xfb, 0x25, 0x5e,
+        ];
+        let aad: [u8; 22] = [
+            0xc3, 0x00, 0x00, 0x00, 0x01, 0x08, 0x83, 0x94, 0xc8, 0xf0, 0x3e, 0x51, 0x57, 0x08,
+            0x00, 0x00, 0x44, 0x9e, 0x00, 0x00, 0x00, 0x02,
+        ];
+        let ciphertext_and_tag: [u8; 48] = [
+            0xbf, 0xd4, 0xe5, 0x10, 0xb9, 0x48, 0xee, 0x20, 0x86, 0x71, 0x19, 0xd9, 0x39, 0x0c,
+            0x5d, 0xc6, 0x8e, 0x23, 0x87, 0x0e, 0xed, 0x86, 0xb0, 0xe2, 0x81, 0x06, 0xca, 0xa4,
+            0xdd, 0x47, 0x1b, 0x24, 0x01, 0xdb, 0x2f, 0xa5, 0x76, 0xfa, 0xf5, 0x6b, 0x6c, 0x00,
+            0x9e, 0x34, 0x85, 0x9b, 0x8d, 0x90,
+        ];
+        let plaintext =
+            quic_aead_decrypt(&RFC9001_CLIENT_KEY, &nonce, &aad, &ciphertext_and_tag).unwrap();
+        assert_eq!(plaintext, b"hello quic crypto frame payload!");
+    }
+
+    #[test]
+    fn quic_aead_decrypt_rejects_tampered_ciphertext() {
+        let nonce = [
+            0xfa, 0x04, 0x4b, 0x2f, 0x42, 0xa3, 0xfd, 0x3b, 0x46, 0xfb, 0x25, 0x5e,
+        ];
+        let aad: [u8; 22] = [
+            0xc3, 0x00, 0x00, 0x00, 0x01, 0x08, 0x83, 0x94, 0xc8, 0xf0, 0x3e, 0x51, 0x57, 0x08,
+            0x00, 0x00, 0x44, 0x9e, 0x00, 0x00, 0x00, 0x02,
+        ];
+        let mut tampered: [u8; 48] = [
+            0xbf, 0xd4, 0xe5, 0x10, 0xb9, 0x48, 0xee, 0x20, 0x86, 0x71, 0x19, 0xd9, 0x39, 0x0c,
+            0x5d, 0xc6, 0x8e, 0x23, 0x87, 0x0e, 0xed, 0x86, 0xb0, 0xe2, 0x81, 0x06, 0xca, 0xa4,
+            0xdd, 0x47, 0x1b, 0x24, 0x01, 0xdb, 0x2f, 0xa5, 0x76, 0xfa, 0xf5, 0x6b, 0x6c, 0x00,
+            0x9e, 0x34, 0x85, 0x9b, 0x8d, 0x90,
+        ];
+        tampered[0] ^= 0xff;
+        assert!(quic_aead_decrypt(&RFC9001_CLIENT_KEY, &nonce, &aad, &tampered).is_none());
+    }
+}